@@ -1,6 +1,19 @@
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+// `std::time::Instant`/`SystemTime` panic on wasm32-unknown-unknown; `web-time`
+// is a drop-in replacement backed by `Performance.now()`/`Date.now()` there,
+// and simply re-exports the std types on every other target.
+use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
 use error_iter::ErrorIter as _;
 use log::{debug, error};
 use pixels::{Error, Pixels, SurfaceTexture};
+#[cfg(target_arch = "wasm32")]
+use pixels::PixelsBuilder;
 use winit::event::VirtualKeyCode;
 use winit::{
     dpi::LogicalSize,
@@ -10,11 +23,40 @@ use winit::{
 };
 use winit_input_helper::WinitInputHelper;
 
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
 const WIDTH: u32 = 400;
 const HEIGHT: u32 = 300;
 
+/// Defaults applied when periodic re-seeding is toggled on via
+/// [`GameOfLife::toggle_seed_schedule`].
+const DEFAULT_SEED_INTERVAL: u64 = 200;
+const DEFAULT_SEED_POPULATION: usize = 10;
+
+/// Path used by the `S`/`O` save/load key bindings.
+const PATTERN_FILE: &str = "pattern.cells";
+
+#[cfg(not(target_arch = "wasm32"))]
 fn main() -> Result<(), Error> {
     env_logger::init();
+    pollster::block_on(run())
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(start)]
+pub fn main_wasm() {
+    console_error_panic_hook::set_once();
+    console_log::init_with_level(log::Level::Warn).expect("error initializing logger");
+    wasm_bindgen_futures::spawn_local(async {
+        run().await.expect("run() failed");
+    });
+}
+
+/// Builds the window, the `Pixels` surface, and the game, then drives the
+/// event loop. Factored out of `main` so the wasm entry point can set up the
+/// same pipeline behind an async `Pixels` surface creation.
+async fn run() -> Result<(), Error> {
     let event_loop = EventLoop::new();
     let mut input = WinitInputHelper::new();
 
@@ -29,17 +71,43 @@ fn main() -> Result<(), Error> {
             .unwrap()
     };
 
-    let mut pixels = {
+    #[cfg(target_arch = "wasm32")]
+    attach_canvas(&window);
+
+    let pixels = {
         let window_size = window.inner_size();
         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-        Pixels::new(WIDTH, HEIGHT, surface_texture)?
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Pixels::new(WIDTH, HEIGHT, surface_texture)?
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            PixelsBuilder::new(WIDTH, HEIGHT, surface_texture)
+                .build_async()
+                .await?
+        }
     };
+    let pixels = Rc::new(RefCell::new(pixels));
+
+    #[cfg(target_arch = "wasm32")]
+    install_resize_listener(Rc::clone(&pixels));
 
     let mut game = GameOfLife::new(WIDTH as usize, HEIGHT as usize);
     let mut paused = false;
+    let mut last_cursor_cell: Option<(usize, usize)> = None;
+
+    // Simulation speed is decoupled from the render loop: an accumulator of
+    // elapsed wall-clock time drives how many generations run per frame,
+    // possibly several in a row to catch up after a slow frame.
+    let mut generations_per_second: f64 = 10.0;
+    let mut accumulator = 0.0f64;
+    let mut last_tick = Instant::now();
+    const FAST_FORWARD_MULTIPLIER: f64 = 4.0;
 
     event_loop.run(move |event, _, control_flow| {
         if let Event::RedrawRequested(_) = event {
+            let mut pixels = pixels.borrow_mut();
             game.draw(pixels.frame_mut());
             if let Err(e) = pixels.render() {
                 error!("pixels.render() failed: {}", e);
@@ -62,15 +130,87 @@ fn main() -> Result<(), Error> {
             if input.key_pressed(VirtualKeyCode::R) {
                 game.starting_position();
             }
+            if input.key_pressed(VirtualKeyCode::G) {
+                game.randomize(0.3);
+            }
+            if input.key_pressed(VirtualKeyCode::T) {
+                let enabled = game.toggle_seed_schedule();
+                debug!("periodic re-seeding: {}", if enabled { "on" } else { "off" });
+            }
+            if input.key_pressed(VirtualKeyCode::B) {
+                let backend = game.toggle_backend();
+                debug!("grid backend: {backend}");
+            }
+            if input.key_pressed(VirtualKeyCode::S) {
+                if let Err(err) = game.save_pattern_file(PATTERN_FILE) {
+                    log_error("save_pattern_file", err);
+                }
+            }
+            if input.key_pressed(VirtualKeyCode::O) {
+                if let Err(err) = game.load_pattern_file(PATTERN_FILE, 0, 0) {
+                    log_error("load_pattern_file", err);
+                }
+            }
+            if input.key_pressed(VirtualKeyCode::C) {
+                let rule_name = game.cycle_rule();
+                debug!("switched to rule: {rule_name}");
+            }
+            if input.key_pressed(VirtualKeyCode::Equals) {
+                generations_per_second = (generations_per_second * 1.5).min(240.0);
+            }
+            if input.key_pressed(VirtualKeyCode::Minus) {
+                generations_per_second = (generations_per_second / 1.5).max(0.5);
+            }
             if let Some(size) = input.window_resized() {
-                if let Err(err) = pixels.resize_surface(size.width, size.height) {
+                if let Err(err) = pixels.borrow_mut().resize_surface(size.width, size.height) {
                     log_error("pixels.resize_surface", err);
                     *control_flow = ControlFlow::Exit;
                     return;
                 }
             }
-            if !paused || input.key_pressed_os(VirtualKeyCode::Space) {
+
+            if input.mouse_pressed(0) {
+                if let Some((mx, my)) = input.mouse() {
+                    if let Ok((px, py)) = pixels.borrow().window_pos_to_pixel((mx, my)) {
+                        game.toggle_cell(px, py);
+                        last_cursor_cell = Some((px, py));
+                    }
+                }
+            } else if input.mouse_held(0) {
+                if let Some((mx, my)) = input.mouse() {
+                    if let Ok((px, py)) = pixels.borrow().window_pos_to_pixel((mx, my)) {
+                        if let Some((lx, ly)) = last_cursor_cell {
+                            game.paint_line(lx, ly, px, py);
+                        }
+                        last_cursor_cell = Some((px, py));
+                    }
+                }
+            } else {
+                last_cursor_cell = None;
+            }
+
+            let now = Instant::now();
+            accumulator += now.duration_since(last_tick).as_secs_f64();
+            last_tick = now;
+
+            if input.key_pressed_os(VirtualKeyCode::Space) {
                 game.update();
+                accumulator = 0.0;
+            } else if !paused {
+                let fast_forward = input.key_held(VirtualKeyCode::Tab);
+                let rate = generations_per_second
+                    * if fast_forward {
+                        FAST_FORWARD_MULTIPLIER
+                    } else {
+                        1.0
+                    };
+                let step_duration = 1.0 / rate;
+                while accumulator >= step_duration {
+                    game.update();
+                    accumulator -= step_duration;
+                }
+            } else {
+                accumulator = 0.0;
             }
             window.request_redraw();
         }
@@ -84,13 +224,165 @@ fn log_error<E: std::error::Error + 'static>(method_name: &str, err: E) {
     }
 }
 
-struct GameOfLife {
+/// Sizes the window to the browser's viewport and appends its canvas to the
+/// document body.
+#[cfg(target_arch = "wasm32")]
+fn attach_canvas(window: &winit::window::Window) {
+    use winit::platform::web::WindowExtWebSys;
+
+    let web_window = web_sys::window().expect("no global `window` exists");
+    let width = web_window.inner_width().unwrap().as_f64().unwrap();
+    let height = web_window.inner_height().unwrap().as_f64().unwrap();
+    window.set_inner_size(LogicalSize::new(width, height));
+
+    let document = web_window.document().expect("window has no document");
+    let body = document.body().expect("document has no body");
+    body.append_child(&web_sys::Element::from(window.canvas()))
+        .expect("couldn't append canvas to document body");
+}
+
+/// Installs a `resize` listener on the browser window that keeps the
+/// `Pixels` surface in sync with the viewport size.
+#[cfg(target_arch = "wasm32")]
+fn install_resize_listener(pixels: Rc<RefCell<Pixels>>) {
+    use wasm_bindgen::JsCast;
+
+    let web_window = web_sys::window().expect("no global `window` exists");
+    let on_resize = Closure::<dyn FnMut()>::new(move || {
+        let web_window = web_sys::window().expect("no global `window` exists");
+        let width = web_window.inner_width().unwrap().as_f64().unwrap() as u32;
+        let height = web_window.inner_height().unwrap().as_f64().unwrap() as u32;
+        if let Err(err) = pixels.borrow_mut().resize_surface(width, height) {
+            log_error("pixels.resize_surface", err);
+        }
+    });
+    web_window
+        .add_event_listener_with_callback("resize", on_resize.as_ref().unchecked_ref())
+        .expect("failed to install resize listener");
+    on_resize.forget();
+}
+
+/// A cellular automaton rule in `B.../S...` notation (e.g. `B3/S23` for
+/// Conway's Life, `B36/S23` for HighLife, `B2/S` for Seeds). `birth[n]` is
+/// true when a dead cell with `n` live neighbors is born; `survival[n]` is
+/// true when a live cell with `n` live neighbors survives.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Rule {
+    birth: [bool; 9],
+    survival: [bool; 9],
+}
+
+impl Rule {
+    /// The presets cycled through at runtime, in order.
+    const PRESETS: [(&'static str, &'static str); 4] = [
+        ("Conway", "B3/S23"),
+        ("HighLife", "B36/S23"),
+        ("Seeds", "B2/S"),
+        ("Day & Night", "B3678/S34678"),
+    ];
+
+    fn conway() -> Self {
+        Self::parse("B3/S23").expect("B3/S23 is a valid rulestring")
+    }
+
+    /// Parses standard `B.../S...` notation into birth/survival lookup
+    /// tables indexed by neighbor count (0-8).
+    fn parse(rulestring: &str) -> Result<Self, String> {
+        let (birth_part, survival_part) = rulestring
+            .split_once('/')
+            .ok_or_else(|| format!("missing '/' in rulestring {rulestring:?}"))?;
+
+        let birth_digits = birth_part
+            .strip_prefix(['B', 'b'])
+            .ok_or_else(|| format!("expected 'B' before '/' in rulestring {rulestring:?}"))?;
+        let survival_digits = survival_part
+            .strip_prefix(['S', 's'])
+            .ok_or_else(|| format!("expected 'S' after '/' in rulestring {rulestring:?}"))?;
+
+        let mut birth = [false; 9];
+        for digit in birth_digits.chars() {
+            let n = digit
+                .to_digit(10)
+                .filter(|&n| n <= 8)
+                .ok_or_else(|| format!("invalid birth digit {digit:?} in {rulestring:?}"))?
+                as usize;
+            birth[n] = true;
+        }
+
+        let mut survival = [false; 9];
+        for digit in survival_digits.chars() {
+            let n = digit
+                .to_digit(10)
+                .filter(|&n| n <= 8)
+                .ok_or_else(|| format!("invalid survival digit {digit:?} in {rulestring:?}"))?
+                as usize;
+            survival[n] = true;
+        }
+
+        Ok(Self { birth, survival })
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+/// A tiny xorshift64 PRNG. Good enough for cosmetic randomization; not
+/// suitable for anything security-sensitive.
+struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    fn seeded_from_time() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x2545_F491_4F6C_DD1D);
+        Self {
+            state: seed.max(1), // xorshift needs a non-zero state
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Returns a pseudo-random value in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+/// Storage backend for the board's live/dead cells. `GameOfLife` talks to
+/// whichever backend it was built with through this trait, so `draw`,
+/// `draw_terminal`, and `update` don't need to know if the board is a dense
+/// grid or a sparse set of live cells.
+trait Grid {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    fn is_alive(&self, row: usize, col: usize) -> bool;
+    fn set(&mut self, row: usize, col: usize, alive: bool);
+    fn update(&mut self, rule: &Rule);
+}
+
+/// The original backend: a dense `width * height` matrix, scanned in full
+/// every generation. Cheap and cache-friendly for small, densely-populated
+/// boards.
+struct DenseGrid {
     width: usize,
     height: usize,
-    cells: Vec<Vec<bool>>, // true = alive, false = dea
+    cells: Vec<Vec<bool>>, // true = alive, false = dead
 }
 
-impl GameOfLife {
+impl DenseGrid {
     fn new(width: usize, height: usize) -> Self {
         Self {
             width,
@@ -99,23 +391,12 @@ impl GameOfLife {
         }
     }
 
-    fn update(&mut self) {
-        let new_cells = (0..self.height)
-            .map(|row| {
-                (0..self.width)
-                    .map(|col| self.compute_next_states(row, col))
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
-        self.cells = new_cells;
-    }
-
-    fn compute_next_states(&self, row: usize, col: usize) -> bool {
+    fn compute_next_state(&self, row: usize, col: usize, rule: &Rule) -> bool {
         let alive_neighbors = self.count_neighbors(row, col);
-        match (self.cells[row][col], alive_neighbors) {
-            (true, 2) | (true, 3) => true,
-            (false, 3) => true,
-            _ => false,
+        if self.cells[row][col] {
+            rule.survival[alive_neighbors]
+        } else {
+            rule.birth[alive_neighbors]
         }
     }
 
@@ -137,12 +418,267 @@ impl GameOfLife {
         }
         count
     }
+}
+
+impl Grid for DenseGrid {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn is_alive(&self, row: usize, col: usize) -> bool {
+        self.cells[row][col]
+    }
+
+    fn set(&mut self, row: usize, col: usize, alive: bool) {
+        self.cells[row][col] = alive;
+    }
+
+    fn update(&mut self, rule: &Rule) {
+        let new_cells = (0..self.height)
+            .map(|row| {
+                (0..self.width)
+                    .map(|col| self.compute_next_state(row, col, rule))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        self.cells = new_cells;
+    }
+}
+
+/// A sparse backend that tracks only the coordinates of live cells, so a
+/// near-empty or near-unbounded board costs work proportional to its
+/// population rather than to `width * height`. `width`/`height` still define
+/// the viewport used for rendering and, when `wrap` is set, the boundary
+/// cells are stepped on toroidally; otherwise the coordinate space is
+/// unbounded in both directions.
+struct SparseGrid {
+    width: usize,
+    height: usize,
+    wrap: bool,
+    live: BTreeSet<(i64, i64)>,
+}
+
+impl SparseGrid {
+    fn new(width: usize, height: usize, wrap: bool) -> Self {
+        Self {
+            width,
+            height,
+            wrap,
+            live: BTreeSet::new(),
+        }
+    }
+
+    fn normalize(&self, x: i64, y: i64) -> (i64, i64) {
+        if self.wrap {
+            let w = self.width as i64;
+            let h = self.height as i64;
+            (x.rem_euclid(w), y.rem_euclid(h))
+        } else {
+            (x, y)
+        }
+    }
+}
+
+impl Grid for SparseGrid {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn is_alive(&self, row: usize, col: usize) -> bool {
+        self.live.contains(&(col as i64, row as i64))
+    }
+
+    fn set(&mut self, row: usize, col: usize, alive: bool) {
+        let pos = (col as i64, row as i64);
+        if alive {
+            self.live.insert(pos);
+        } else {
+            self.live.remove(&pos);
+        }
+    }
+
+    fn update(&mut self, rule: &Rule) {
+        // Seed every live cell with a count of 0 so a cell with no live
+        // neighbors is still considered below, matching the dense backend's
+        // full scan (needed for rules where `survival[0]` is true, e.g. S0).
+        let mut neighbor_counts: HashMap<(i64, i64), u8> =
+            self.live.iter().map(|&pos| (pos, 0u8)).collect();
+        for &(x, y) in &self.live {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    let neighbor = self.normalize(x + dx, y + dy);
+                    *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut next_live = BTreeSet::new();
+        for (pos, count) in neighbor_counts {
+            let currently_alive = self.live.contains(&pos);
+            let alive_next = if currently_alive {
+                rule.survival[count as usize]
+            } else {
+                rule.birth[count as usize]
+            };
+            if alive_next {
+                next_live.insert(pos);
+            }
+        }
+        self.live = next_live;
+    }
+}
+
+struct GameOfLife {
+    grid: Box<dyn Grid>,
+    sparse: bool,
+    /// Only meaningful while `sparse` is set: whether the sparse backend
+    /// wraps at the `width`/`height` viewport (a torus) or is left unbounded.
+    wrap: bool,
+    rule: Rule,
+    rule_index: usize,
+    rng: Xorshift64,
+    generation: u64,
+    seed_interval: u64,
+    seed_population: usize,
+}
+
+impl GameOfLife {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            grid: Box::new(DenseGrid::new(width, height)),
+            sparse: false,
+            wrap: true,
+            rule: Rule::default(),
+            rule_index: 0,
+            rng: Xorshift64::seeded_from_time(),
+            generation: 0,
+            seed_interval: 0,
+            seed_population: 0,
+        }
+    }
+
+    /// Cycles the storage backend through dense -> sparse (wrapped) -> sparse
+    /// (unbounded) -> dense, carrying over the current live cells within the
+    /// visible board at each step. Returns a name for the new backend for
+    /// display/logging.
+    fn toggle_backend(&mut self) -> &'static str {
+        let (width, height) = (self.width(), self.height());
+        let live_cells: Vec<(usize, usize)> = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .filter(|&(row, col)| self.grid.is_alive(row, col))
+            .collect();
+
+        let name = if !self.sparse {
+            self.sparse = true;
+            self.wrap = true;
+            "sparse (wrapped)"
+        } else if self.wrap {
+            self.wrap = false;
+            "sparse (unbounded)"
+        } else {
+            self.sparse = false;
+            "dense"
+        };
+
+        self.grid = if self.sparse {
+            Box::new(SparseGrid::new(width, height, self.wrap))
+        } else {
+            Box::new(DenseGrid::new(width, height))
+        };
+        for (row, col) in live_cells {
+            self.grid.set(row, col, true);
+        }
+        name
+    }
+
+    /// Fills the board by sampling each cell alive independently with
+    /// probability `density`.
+    fn randomize(&mut self, density: f64) {
+        let (width, height) = (self.width(), self.height());
+        for row in 0..height {
+            for col in 0..width {
+                let alive = self.rng.next_f64() < density;
+                self.grid.set(row, col, alive);
+            }
+        }
+    }
+
+    /// Configures periodic re-seeding: every `seed_interval` generations,
+    /// `seed_population` random cells within the board are set alive. Pass
+    /// `seed_interval: 0` to disable.
+    fn set_seed_schedule(&mut self, seed_interval: u64, seed_population: usize) {
+        self.seed_interval = seed_interval;
+        self.seed_population = seed_population;
+    }
+
+    /// Flips periodic re-seeding on or off, using [`DEFAULT_SEED_INTERVAL`]
+    /// and [`DEFAULT_SEED_POPULATION`] when turning it on. Returns whether
+    /// re-seeding is now enabled.
+    fn toggle_seed_schedule(&mut self) -> bool {
+        if self.seed_interval == 0 {
+            self.set_seed_schedule(DEFAULT_SEED_INTERVAL, DEFAULT_SEED_POPULATION);
+        } else {
+            self.set_seed_schedule(0, 0);
+        }
+        self.seed_interval > 0
+    }
+
+    /// Drops `population` random live cells onto the board, keeping an
+    /// otherwise-stabilizing colony alive.
+    fn sprinkle(&mut self, population: usize) {
+        let (width, height) = (self.width(), self.height());
+        if width == 0 || height == 0 {
+            return;
+        }
+        for _ in 0..population {
+            let row = (self.rng.next_u64() % height as u64) as usize;
+            let col = (self.rng.next_u64() % width as u64) as usize;
+            self.grid.set(row, col, true);
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    fn update(&mut self) {
+        self.grid.update(&self.rule);
+        self.generation += 1;
+        if self.seed_interval > 0 && self.generation % self.seed_interval == 0 {
+            self.sprinkle(self.seed_population);
+        }
+    }
+
+    /// Switches to the next rule in [`Rule::PRESETS`], wrapping around, and
+    /// returns its name for display/logging.
+    fn cycle_rule(&mut self) -> &'static str {
+        self.rule_index = (self.rule_index + 1) % Rule::PRESETS.len();
+        let (name, rulestring) = Rule::PRESETS[self.rule_index];
+        self.rule = Rule::parse(rulestring).expect("presets are valid rulestrings");
+        name
+    }
 
     fn draw(&self, screen: &mut [u8]) {
-        for row in 0..self.height {
-            for col in 0..self.width {
-                let index = (row * self.width + col) * 4;
-                let color = if self.cells[row][col] {
+        let width = self.width();
+        for row in 0..self.height() {
+            for col in 0..width {
+                let index = (row * width + col) * 4;
+                let color = if self.grid.is_alive(row, col) {
                     [0x00, 0x00, 0x00]
                 } else {
                     [0xFF, 0xFF, 0xFF]
@@ -156,9 +692,9 @@ impl GameOfLife {
     }
 
     fn draw_terminal(&self) {
-        for row in 0..self.height {
-            for col in 0..self.width {
-                let symbol = if self.cells[row][col] {
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                let symbol = if self.grid.is_alive(row, col) {
                     "◼︎"
                 } else {
                     "◻︎"
@@ -178,9 +714,51 @@ impl GameOfLife {
         }
     }
 
+    fn set_cell(&mut self, col: usize, row: usize, alive: bool) {
+        if row < self.height() && col < self.width() {
+            self.grid.set(row, col, alive);
+        }
+    }
+
+    fn toggle_cell(&mut self, col: usize, row: usize) {
+        if row < self.height() && col < self.width() {
+            let alive = self.grid.is_alive(row, col);
+            self.grid.set(row, col, !alive);
+        }
+    }
+
+    /// Paints a continuous line of live cells between two pixel-space points
+    /// using Bresenham's line algorithm, so fast mouse drags don't leave gaps.
+    fn paint_line(&mut self, x0: usize, y0: usize, x1: usize, y1: usize) {
+        let (mut x0, mut y0) = (x0 as i64, y0 as i64);
+        let (x1, y1) = (x1 as i64, y1 as i64);
+
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            self.set_cell(x0 as usize, y0 as usize, true);
+            if x0 == x1 && y0 == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y0 += sy;
+            }
+        }
+    }
+
     fn starting_position(&mut self) {
-        let middle_row = self.height / 2;
-        let middle_col = self.width / 2;
+        let middle_row = self.height() / 2;
+        let middle_col = self.width() / 2;
 
         let offsets = [(-1, -1), (-1, 0), (0, -2), (0, -1), (1, -1)];
 
@@ -188,9 +766,131 @@ impl GameOfLife {
             let row = (middle_row as isize + dr) as usize;
             let col = (middle_col as isize + dc) as usize;
 
-            if row < self.height && col < self.width {
-                self.cells[row][col] = true;
+            if row < self.height() && col < self.width() {
+                self.grid.set(row, col, true);
+            }
+        }
+    }
+
+    /// Stamps live cells from a parsed pattern (row, col offsets relative to
+    /// its own top-left corner) onto the board, anchored at (offset_row,
+    /// offset_col).
+    fn stamp_pattern(&mut self, cells: &[(usize, usize)], offset_row: usize, offset_col: usize) {
+        for &(row, col) in cells {
+            self.set_cell(offset_col + col, offset_row + row, true);
+        }
+    }
+
+    /// Loads a pattern file and stamps it onto the board at the given
+    /// offset. Dispatches on the file extension: `.rle` is parsed as Conway
+    /// RLE, anything else as the plaintext `.cells` format. If the pattern
+    /// is RLE and carries its own `rule = ...` header, the board switches to
+    /// that rule rather than continuing to evolve under whatever was active.
+    fn load_pattern_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        offset_row: usize,
+        offset_col: usize,
+    ) -> io::Result<()> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)?;
+        let cells = if path.extension().and_then(|ext| ext.to_str()) == Some("rle") {
+            let (cells, rule) = parse_rle(&contents);
+            if let Some(rule) = rule {
+                self.rule = rule;
+            }
+            cells
+        } else {
+            parse_plaintext(&contents)
+        };
+        self.stamp_pattern(&cells, offset_row, offset_col);
+        Ok(())
+    }
+
+    /// Saves the current board to `path` in the plaintext `.cells` format.
+    fn save_pattern_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut contents = String::with_capacity((self.width() + 1) * self.height());
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                contents.push(if self.grid.is_alive(row, col) {
+                    'O'
+                } else {
+                    '.'
+                });
+            }
+            contents.push('\n');
+        }
+        fs::write(path, contents)
+    }
+}
+
+/// Parses the plaintext `.cells` format: `!`-prefixed lines are comments,
+/// every other line is a row of `.` (dead) and `O` (alive) characters.
+/// Returns live cells as (row, col) offsets from the pattern's top-left.
+fn parse_plaintext(contents: &str) -> Vec<(usize, usize)> {
+    let mut cells = Vec::new();
+    for (row, line) in contents.lines().filter(|line| !line.starts_with('!')).enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            if ch == 'O' {
+                cells.push((row, col));
+            }
+        }
+    }
+    cells
+}
+
+/// Parses a Conway RLE (`.rle`) pattern body. The `#`-prefixed comment lines
+/// are skipped; the `x = M, y = N, rule = ...` header is scanned for its
+/// `rule` field (returned alongside the cells, if present and valid); the
+/// run-length encoded body uses a numeric run count followed by a tag: `b`
+/// (dead), `o` (alive), `$` (end of row), `!` (end of pattern). A tag with
+/// no preceding count means a run of one. Returns live cells as (row, col)
+/// offsets from the pattern's top-left.
+fn parse_rle(contents: &str) -> (Vec<(usize, usize)>, Option<Rule>) {
+    let mut cells = Vec::new();
+    let mut rule = None;
+    let mut row = 0usize;
+    let mut col = 0usize;
+    let mut run = String::new();
+
+    'lines: for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('x') || line.starts_with('X') {
+            rule = line.split(',').find_map(|field| {
+                let (key, value) = field.trim().split_once('=')?;
+                key.trim()
+                    .eq_ignore_ascii_case("rule")
+                    .then(|| Rule::parse(value.trim()).ok())
+                    .flatten()
+            });
+            continue;
+        }
+        for ch in line.chars() {
+            match ch {
+                '0'..='9' => run.push(ch),
+                'b' | 'o' | '$' => {
+                    let count = run.parse::<usize>().unwrap_or(1);
+                    run.clear();
+                    match ch {
+                        'b' => col += count,
+                        'o' => {
+                            cells.extend((0..count).map(|i| (row, col + i)));
+                            col += count;
+                        }
+                        '$' => {
+                            row += count;
+                            col = 0;
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                '!' => break 'lines,
+                _ => {}
             }
         }
     }
+    (cells, rule)
 }